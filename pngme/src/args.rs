@@ -1,7 +1,15 @@
-use clap::{Subcommand, Args};
+use clap::{Subcommand, Args, ValueEnum};
 use std::path::PathBuf;
 use crate::chunk_type::ChunkType;
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Encoding {
+    #[default]
+    Raw,
+    Base64,
+    Hex,
+}
+
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum PngMeArgs {
@@ -9,20 +17,51 @@ pub enum PngMeArgs {
     Decode(DecodeArgs),
     Remove(RemoveArgs),
     Print(PrintArgs),
+    Sign(SignArgs),
+    Verify(VerifyArgs),
 }
 
 #[derive(Clone, Debug, Args)]
 pub struct EncodeArgs {
     pub file_path: PathBuf,
     pub chunk_type: ChunkType,
-    pub message: String,
+    pub message: Option<String>,
+    #[arg(long, conflicts_with = "message")]
+    pub input_file: Option<PathBuf>,
+    #[arg(long = "output-file", short = 'o')]
     pub output_file: Option<PathBuf>,
+    #[arg(long, value_enum, default_value_t = Encoding::Raw)]
+    pub encoding: Encoding,
+    #[arg(long)]
+    pub passphrase: Option<String>,
+    #[arg(long, requires = "passphrase")]
+    pub cipher: Option<String>,
+    #[arg(long)]
+    pub split: Option<usize>,
+    #[arg(long = "field", value_parser = parse_field)]
+    pub fields: Vec<(u8, Vec<u8>)>,
+}
+
+fn parse_field(s: &str) -> std::result::Result<(u8, Vec<u8>), String> {
+    let (tag, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid --field '{}': expected tag=value", s))?;
+
+    let tag: u8 = tag
+        .parse()
+        .map_err(|_| format!("invalid --field tag '{}': must be a number from 0 to 255", tag))?;
+
+    Ok((tag, value.as_bytes().to_vec()))
 }
 
 #[derive(Clone, Debug, Args)]
 pub struct DecodeArgs {
     pub file_path: PathBuf,
     pub chunk_type: ChunkType,
+    #[arg(long, value_enum, default_value_t = Encoding::Raw)]
+    pub encoding: Encoding,
+    #[arg(long)]
+    pub passphrase: Option<String>,
 }
 
 #[derive(Clone, Debug, Args)]
@@ -35,3 +74,16 @@ pub struct RemoveArgs {
 pub struct PrintArgs {
     pub file_path: PathBuf,
 }
+
+#[derive(Clone, Debug, Args)]
+pub struct SignArgs {
+    pub file_path: PathBuf,
+    pub key_path: PathBuf,
+    #[arg(long = "output-file", short = 'o')]
+    pub output_file: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct VerifyArgs {
+    pub file_path: PathBuf,
+}
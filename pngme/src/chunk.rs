@@ -43,6 +43,7 @@ impl Display for Chunk {
 }
 
 impl Chunk {
+    #[allow(dead_code)]
     pub fn length(&self) -> u32 {
         self.data_length
     }
@@ -52,13 +53,15 @@ impl Chunk {
     }
 
     pub fn data(&self) -> &[u8] {
-        &self.message_bytes.as_slice()
+        self.message_bytes.as_slice()
     }
 
+    #[allow(dead_code)]
     pub fn crc(&self) -> u32 {
         self.crc
     }
 
+    #[allow(dead_code)]
     pub fn data_as_string(&self) -> std::result::Result<String, FromUtf8Error> {
         String::from_utf8(self.message_bytes.clone())
     }
@@ -84,6 +87,55 @@ impl Chunk {
             crc: checksum_ieee(check_bytes),
         }
     }
+
+    pub fn from_fields(fields: &[(u8, Vec<u8>)]) -> Vec<u8> {
+        let mut body = FIELDS_MAGIC.to_vec();
+
+        for (tag, value) in fields {
+            body.push(*tag);
+            body.extend_from_slice(&(value.len() as u32).to_be_bytes());
+            body.extend_from_slice(value);
+        }
+
+        body
+    }
+
+    pub fn fields(&self) -> std::result::Result<Vec<(u8, Vec<u8>)>, &'static str> {
+        parse_fields(&self.message_bytes)
+    }
+}
+
+const FIELDS_MAGIC: [u8; 4] = *b"PmFd";
+
+pub fn is_fields_container(data: &[u8]) -> bool {
+    data.len() >= FIELDS_MAGIC.len() && data[0..4] == FIELDS_MAGIC
+}
+
+pub fn parse_fields(data: &[u8]) -> std::result::Result<Vec<(u8, Vec<u8>)>, &'static str> {
+    if !is_fields_container(data) {
+        return Err("chunk is not a fields container");
+    }
+
+    let mut fields = Vec::new();
+    let mut rest = &data[FIELDS_MAGIC.len()..];
+
+    while !rest.is_empty() {
+        if rest.len() < 5 {
+            return Err("truncated field record");
+        }
+
+        let tag = rest[0];
+        let len = u32::from_be_bytes(rest[1..5].try_into().expect("slice is exactly 4 bytes")) as usize;
+
+        if rest.len() < 5 + len {
+            return Err("field record length overruns the chunk body");
+        }
+
+        fields.push((tag, rest[5..5 + len].to_vec()));
+        rest = &rest[5 + len..];
+    }
+
+    Ok(fields)
 }
 
 
@@ -92,6 +144,33 @@ impl Chunk {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fields_round_trip() {
+        let fields = vec![(1u8, b"Jane".to_vec()), (2u8, b"2024-01-01".to_vec())];
+        let body = Chunk::from_fields(&fields);
+        assert_eq!(parse_fields(&body).unwrap(), fields);
+    }
+
+    #[test]
+    fn test_parse_fields_not_a_container() {
+        assert!(parse_fields(b"not a container").is_err());
+    }
+
+    #[test]
+    fn test_parse_fields_truncated_record_fails() {
+        let mut body = FIELDS_MAGIC.to_vec();
+        body.push(1); // tag, but no length/value follows
+        assert!(parse_fields(&body).is_err());
+    }
+
+    #[test]
+    fn test_parse_fields_overrunning_length_fails() {
+        let mut body = FIELDS_MAGIC.to_vec();
+        body.push(1);
+        body.extend_from_slice(&100u32.to_be_bytes()); // claims 100 bytes, has none
+        assert!(parse_fields(&body).is_err());
+    }
+
     fn testing_chunk() -> Chunk {
         let data_length: u32 = 42;
         let chunk_type = "RuSt".as_bytes();
@@ -2,7 +2,7 @@ use std::convert::TryFrom;
 use std::str::FromStr;
 use std::fmt::Display;
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub struct ChunkType {
     first_byte: u8,
     second_byte: u8,
@@ -29,7 +29,7 @@ impl FromStr for ChunkType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let bytes = s.as_bytes();
         let result = ChunkType {
-            first_byte: *bytes.get(0).unwrap(),
+            first_byte: *bytes.first().unwrap(),
             second_byte: *bytes.get(1).unwrap(),
             third_byte: *bytes.get(2).unwrap(),
             fourth_byte: *bytes.get(3).unwrap(),
@@ -57,9 +57,10 @@ impl ChunkType {
     }
 
     fn is_valid_byte(b: &u8) -> bool {
-        ((&65 <= b) && (b <= &90)) || ((&97 <= b) && (b <= &122))
+        (&65..=&90).contains(&b) || (&97..=&122).contains(&b)
     }
 
+    #[allow(dead_code)]
     pub fn is_valid(&self) -> bool {
         ChunkType::is_valid_byte(&self.first_byte)
         && ChunkType::is_valid_byte(&self.second_byte)
@@ -67,22 +68,27 @@ impl ChunkType {
         && ChunkType::is_valid_byte(&self.fourth_byte)
     }
 
+    #[allow(dead_code)]
     fn is_upper(b: &u8) -> bool {
-        (&65 <= b) && (b <= &90)
+        (&65..=&90).contains(&b)
     }
 
+    #[allow(dead_code)]
     pub fn is_critical(&self) -> bool {
         ChunkType::is_upper(&self.first_byte)
     }
 
+    #[allow(dead_code)]
     pub fn is_public(&self) -> bool {
         ChunkType::is_upper(&self.second_byte)
     }
 
+    #[allow(dead_code)]
     pub fn is_reserved_bit_valid(&self) -> bool {
         ChunkType::is_upper(&self.third_byte)
     }
 
+    #[allow(dead_code)]
     pub fn is_safe_to_copy(&self) -> bool {
         !ChunkType::is_upper(&self.fourth_byte)
     }
@@ -1,6 +1,11 @@
-use crate::args::{PngMeArgs, EncodeArgs, DecodeArgs, PrintArgs, RemoveArgs};
+use crate::args::{PngMeArgs, EncodeArgs, DecodeArgs, Encoding, PrintArgs, RemoveArgs, SignArgs, VerifyArgs};
+use crate::chunk;
 use crate::chunk::Chunk;
+use crate::crypto;
+use crate::encoding;
 use crate::png::Png;
+use crate::signing;
+use crate::split;
 use clap::Parser;
 use std::fs;
 use std::convert::TryFrom;
@@ -18,13 +23,49 @@ struct Cli {
 pub fn execute_command() -> Result<()> {
     let cli = Cli::parse();
     match cli.cmd {
-        PngMeArgs::Encode(EncodeArgs{file_path, chunk_type, message, output_file}) => {
+        PngMeArgs::Encode(EncodeArgs{file_path, chunk_type, message, output_file, input_file, encoding: message_encoding, passphrase, cipher, split: split_size, fields}) => {
             let mut png = Png::try_from(fs::read(&file_path)?.as_slice()).unwrap();
 
-            let chunk = Chunk::new(chunk_type, message.into());
+            if let Some(cipher) = &cipher {
+                if cipher != "aes-gcm" {
+                    return Err(format!("unsupported cipher '{}': only aes-gcm is supported", cipher).into());
+                }
+            }
+
+            let payload = if !fields.is_empty() {
+                if message.is_some() || input_file.is_some() {
+                    return Err("--field cannot be combined with a message or --input-file".into());
+                }
+
+                Chunk::from_fields(&fields)
+            } else {
+                let raw_input = match (input_file, message) {
+                    (Some(input_file), None) => fs::read(input_file)?,
+                    (None, Some(message)) => message.into_bytes(),
+                    (None, None) => return Err("either a message, --input-file, or --field is required".into()),
+                    (Some(_), Some(_)) => unreachable!("clap rejects message and --input-file together"),
+                };
+
+                match message_encoding {
+                    Encoding::Raw => raw_input,
+                    Encoding::Base64 => encoding::base64::decode(std::str::from_utf8(&raw_input)?)?,
+                    Encoding::Hex => encoding::hex::decode(std::str::from_utf8(&raw_input)?)?,
+                }
+            };
+
+            let message_bytes = match &passphrase {
+                Some(passphrase) => crypto::encrypt(passphrase, &payload)?,
+                None => payload,
+            };
+
+            if let Some(piece_size) = split_size {
+                for chunk in split::split_into_chunks(chunk_type.clone(), &message_bytes, piece_size)? {
+                    png.append_chunk(chunk);
+                }
+            } else {
+                png.append_chunk(Chunk::new(chunk_type, message_bytes));
+            }
 
-            png.append_chunk(chunk);
-            
             if let Some(output_file) = output_file {
                 fs::write(output_file, png.as_bytes())?;
             } else {
@@ -33,15 +74,45 @@ pub fn execute_command() -> Result<()> {
 
             Ok(())
         },
-        PngMeArgs::Decode(DecodeArgs{file_path, chunk_type}) => {
+        PngMeArgs::Decode(DecodeArgs{file_path, chunk_type, encoding: output_encoding, passphrase}) => {
             let png = Png::try_from(fs::read(file_path)?.as_slice()).unwrap();
 
-            if let Some(chunk) = png.chunk_by_type(format!("{}", chunk_type).as_str()) {
-                println!("{}", chunk.data_as_string()?);
-                Ok(())
+            let chunks = png.chunks_by_type(format!("{}", chunk_type).as_str());
+
+            if chunks.is_empty() {
+                return Err("chunk does not exist".into());
+            }
+
+            let raw_bytes = if split::is_split_piece(chunks[0].data()) {
+                split::reassemble(&chunks)?
+            } else if chunks.len() == 1 {
+                chunks[0].data().to_vec()
             } else {
-                Err("chunk does not exist".into())
+                return Err(format!(
+                    "found {} chunks of type '{}' but none look like split pieces",
+                    chunks.len(),
+                    chunk_type
+                )
+                .into());
+            };
+
+            let message_bytes = match &passphrase {
+                Some(passphrase) => crypto::decrypt(passphrase, &raw_bytes)?,
+                None => raw_bytes,
+            };
+
+            if chunk::is_fields_container(&message_bytes) {
+                print_fields(&chunk::parse_fields(&message_bytes)?);
+                return Ok(());
+            }
+
+            match output_encoding {
+                Encoding::Raw => println!("{}", String::from_utf8(message_bytes)?),
+                Encoding::Base64 => println!("{}", encoding::base64::encode(&message_bytes)),
+                Encoding::Hex => println!("{}", encoding::hex::encode(&message_bytes)),
             }
+
+            Ok(())
         },
         PngMeArgs::Remove(RemoveArgs{file_path, chunk_type}) => {
             let mut png = Png::try_from(fs::read(&file_path)?.as_slice()).unwrap();
@@ -53,8 +124,59 @@ pub fn execute_command() -> Result<()> {
             Ok(())
         },
         PngMeArgs::Print(PrintArgs{file_path}) => {
-            println!("{:?}", Png::try_from(fs::read(file_path)?.as_slice()).unwrap());
+            let png = Png::try_from(fs::read(file_path)?.as_slice()).unwrap();
+            println!("{:?}", png);
+
+            for chunk in png.chunks() {
+                if let Ok(fields) = chunk.fields() {
+                    println!("\n{} fields:", chunk.chunk_type());
+                    print_fields(&fields);
+                }
+            }
+
+            Ok(())
+        },
+        PngMeArgs::Sign(SignArgs{file_path, key_path, output_file}) => {
+            let mut png = Png::try_from(fs::read(&file_path)?.as_slice())?;
+
+            let _ = png.remove_chunk(signing::SIGNATURE_CHUNK_TYPE);
+
+            let signing_key = signing::load_signing_key(&key_path)?;
+            let digest = signing::digest_signed_chunks(&png);
+            png.append_chunk(signing::signature_chunk(&signing_key, &digest)?);
+
+            if let Some(output_file) = output_file {
+                fs::write(output_file, png.as_bytes())?;
+            } else {
+                fs::write(file_path, png.as_bytes())?;
+            }
+
             Ok(())
         },
+        PngMeArgs::Verify(VerifyArgs{file_path}) => {
+            let png = Png::try_from(fs::read(&file_path)?.as_slice())?;
+
+            let chunk = png
+                .chunk_by_type(signing::SIGNATURE_CHUNK_TYPE)
+                .ok_or("png has no signature chunk")?;
+
+            let digest = signing::digest_signed_chunks(&png);
+
+            if signing::verify_signature(chunk, &digest)? {
+                println!("signature is valid");
+                Ok(())
+            } else {
+                Err("signature is invalid: the PNG may have been tampered with".into())
+            }
+        },
+    }
+}
+
+fn print_fields(fields: &[(u8, Vec<u8>)]) {
+    for (tag, value) in fields {
+        match std::str::from_utf8(value) {
+            Ok(text) => println!("  {}: {}", tag, text),
+            Err(_) => println!("  {}: {}", tag, encoding::hex::encode(value)),
+        }
     }
 }
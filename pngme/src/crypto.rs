@@ -0,0 +1,89 @@
+use aes_gcm::aead::Aead;
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use sha2::Sha256;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+const PBKDF2_ROUNDS: u32 = 100_000;
+
+pub fn encrypt(passphrase: &str, plaintext: &[u8]) -> Result<Vec<u8>> {
+    let mut salt = [0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce_bytes);
+
+    let key = derive_key(passphrase, &salt);
+    let cipher = Aes256Gcm::new_from_slice(&key)?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+        .map_err(|_| "encryption failed")?;
+
+    let mut payload = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    payload.extend_from_slice(&salt);
+    payload.extend_from_slice(&nonce_bytes);
+    payload.extend_from_slice(&ciphertext);
+
+    Ok(payload)
+}
+
+pub fn decrypt(passphrase: &str, payload: &[u8]) -> Result<Vec<u8>> {
+    if payload.len() < SALT_LEN + NONCE_LEN + TAG_LEN {
+        return Err("encrypted payload is too short".into());
+    }
+
+    let salt = &payload[0..SALT_LEN];
+    let nonce_bytes = &payload[SALT_LEN..SALT_LEN + NONCE_LEN];
+    let ciphertext = &payload[SALT_LEN + NONCE_LEN..];
+
+    let key = derive_key(passphrase, salt);
+    let cipher = Aes256Gcm::new_from_slice(&key)?;
+
+    cipher
+        .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+        .map_err(|_| "failed to decrypt: wrong passphrase or the payload was tampered with".into())
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> [u8; KEY_LEN] {
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, PBKDF2_ROUNDS, &mut key);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let payload = encrypt("correct horse", b"hidden message").unwrap();
+        let plaintext = decrypt("correct horse", &payload).unwrap();
+        assert_eq!(plaintext, b"hidden message");
+    }
+
+    #[test]
+    fn test_decrypt_wrong_passphrase_fails() {
+        let payload = encrypt("correct horse", b"hidden message").unwrap();
+        assert!(decrypt("wrong passphrase", &payload).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_tampered_ciphertext_fails() {
+        let mut payload = encrypt("correct horse", b"hidden message").unwrap();
+        let last = payload.len() - 1;
+        payload[last] ^= 0xff;
+        assert!(decrypt("correct horse", &payload).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_too_short_fails() {
+        assert!(decrypt("correct horse", &[0u8; 4]).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub mod base64 {
+    use super::Result;
+
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    pub fn encode(data: &[u8]) -> String {
+        let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+        for group in data.chunks(3) {
+            let b0 = group[0];
+            let b1 = *group.get(1).unwrap_or(&0);
+            let b2 = *group.get(2).unwrap_or(&0);
+            let n = ((b0 as u32) << 16) | ((b1 as u32) << 8) | (b2 as u32);
+
+            out.push(ALPHABET[((n >> 18) & 0x3F) as usize] as char);
+            out.push(ALPHABET[((n >> 12) & 0x3F) as usize] as char);
+            out.push(if group.len() > 1 { ALPHABET[((n >> 6) & 0x3F) as usize] as char } else { '=' });
+            out.push(if group.len() > 2 { ALPHABET[(n & 0x3F) as usize] as char } else { '=' });
+        }
+
+        out
+    }
+
+    pub fn decode(s: &str) -> Result<Vec<u8>> {
+        let trimmed = s.trim_end_matches('=');
+        let mut out = Vec::with_capacity(trimmed.len() / 4 * 3 + 3);
+        let mut buf: u32 = 0;
+        let mut bits: u32 = 0;
+
+        for c in trimmed.bytes() {
+            buf = (buf << 6) | decode_char(c)? as u32;
+            bits += 6;
+
+            if bits >= 8 {
+                bits -= 8;
+                out.push((buf >> bits) as u8);
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn decode_char(c: u8) -> Result<u8> {
+        match c {
+            b'A'..=b'Z' => Ok(c - b'A'),
+            b'a'..=b'z' => Ok(c - b'a' + 26),
+            b'0'..=b'9' => Ok(c - b'0' + 52),
+            b'+' => Ok(62),
+            b'/' => Ok(63),
+            _ => Err(format!("invalid base64 character '{}'", c as char).into()),
+        }
+    }
+}
+
+pub mod hex {
+    use super::Result;
+    use std::fmt::Write;
+
+    pub fn encode(data: &[u8]) -> String {
+        let mut out = String::with_capacity(data.len() * 2);
+        for byte in data {
+            write!(out, "{:02x}", byte).expect("writing to a String cannot fail");
+        }
+        out
+    }
+
+    pub fn decode(s: &str) -> Result<Vec<u8>> {
+        if !s.len().is_multiple_of(2) {
+            return Err("hex string must have an even number of digits".into());
+        }
+
+        let mut out = Vec::with_capacity(s.len() / 2);
+        for i in (0..s.len()).step_by(2) {
+            let byte = u8::from_str_radix(&s[i..i + 2], 16)
+                .map_err(|_| format!("invalid hex byte '{}'", &s[i..i + 2]))?;
+            out.push(byte);
+        }
+
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_round_trip_no_remainder() {
+        let encoded = base64::encode(b"abc");
+        assert_eq!(encoded, "YWJj");
+        assert_eq!(base64::decode(&encoded).unwrap(), b"abc");
+    }
+
+    #[test]
+    fn test_base64_round_trip_two_remainder_bytes() {
+        let encoded = base64::encode(b"ab");
+        assert_eq!(encoded, "YWI=");
+        assert_eq!(base64::decode(&encoded).unwrap(), b"ab");
+    }
+
+    #[test]
+    fn test_base64_round_trip_one_remainder_byte() {
+        let encoded = base64::encode(b"a");
+        assert_eq!(encoded, "YQ==");
+        assert_eq!(base64::decode(&encoded).unwrap(), b"a");
+    }
+
+    #[test]
+    fn test_base64_decode_invalid_character_fails() {
+        assert!(base64::decode("!!!!").is_err());
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let encoded = hex::encode(b"\x00\xffab");
+        assert_eq!(encoded, "00ff6162");
+        assert_eq!(hex::decode(&encoded).unwrap(), b"\x00\xffab");
+    }
+
+    #[test]
+    fn test_hex_decode_odd_length_fails() {
+        assert!(hex::decode("abc").is_err());
+    }
+}
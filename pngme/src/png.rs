@@ -0,0 +1,102 @@
+use crate::chunk::Chunk;
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+#[derive(Debug)]
+pub struct Png {
+    header: [u8; 8],
+    chunks: Vec<Chunk>,
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 8 {
+            return Err("not enough bytes for a PNG header".into());
+        }
+
+        let header: [u8; 8] = bytes[0..8].try_into()?;
+        if header != STANDARD_HEADER {
+            return Err("not a valid PNG file".into());
+        }
+
+        let mut chunks = Vec::new();
+        let mut remaining = &bytes[8..];
+
+        while !remaining.is_empty() {
+            if remaining.len() < 12 {
+                return Err("truncated chunk".into());
+            }
+
+            let data_length_bytes: [u8; 4] = remaining[0..4].try_into()?;
+            let chunk_len = 12 + u32::from_be_bytes(data_length_bytes) as usize;
+
+            if remaining.len() < chunk_len {
+                return Err("truncated chunk".into());
+            }
+
+            chunks.push(Chunk::try_from(&remaining[0..chunk_len])?);
+            remaining = &remaining[chunk_len..];
+        }
+
+        Ok(Png { header, chunks })
+    }
+}
+
+impl Display for Png {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Png {{")?;
+        writeln!(f, "  header: {:?}", self.header)?;
+        writeln!(f, "  chunks: {}", self.chunks.len())?;
+        write!(f, "}}")
+    }
+}
+
+impl Png {
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    pub fn remove_chunk(&mut self, chunk_type: &str) -> Result<Chunk> {
+        let pos = self
+            .chunks
+            .iter()
+            .position(|chunk| chunk.chunk_type().to_string() == chunk_type)
+            .ok_or("chunk does not exist")?;
+
+        Ok(self.chunks.remove(pos))
+    }
+
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|chunk| chunk.chunk_type().to_string() == chunk_type)
+    }
+
+    pub fn chunks_by_type(&self, chunk_type: &str) -> Vec<&Chunk> {
+        self.chunks
+            .iter()
+            .filter(|chunk| chunk.chunk_type().to_string() == chunk_type)
+            .collect()
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.header.to_vec();
+
+        for chunk in &self.chunks {
+            bytes.extend(chunk.as_bytes());
+        }
+
+        bytes
+    }
+}
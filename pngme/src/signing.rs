@@ -0,0 +1,107 @@
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+use crate::png::Png;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub const SIGNATURE_CHUNK_TYPE: &str = "siGn";
+
+pub fn load_signing_key(path: &Path) -> Result<SigningKey> {
+    let bytes = fs::read(path)?;
+    let seed: [u8; 32] = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| "signing key file must contain exactly 32 raw bytes")?;
+
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+pub fn digest_signed_chunks(png: &Png) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+
+    for chunk in png.chunks() {
+        if chunk.chunk_type().to_string() == SIGNATURE_CHUNK_TYPE {
+            continue;
+        }
+
+        hasher.update(chunk.chunk_type().bytes());
+        hasher.update(chunk.data());
+    }
+
+    hasher.finalize().into()
+}
+
+pub fn signature_chunk(signing_key: &SigningKey, digest: &[u8; 32]) -> Result<Chunk> {
+    let signature = signing_key.sign(digest);
+
+    let mut data = Vec::with_capacity(32 + 64);
+    data.extend_from_slice(signing_key.verifying_key().as_bytes());
+    data.extend_from_slice(&signature.to_bytes());
+
+    Ok(Chunk::new(ChunkType::from_str(SIGNATURE_CHUNK_TYPE)?, data))
+}
+
+pub fn verify_signature(chunk: &Chunk, digest: &[u8; 32]) -> Result<bool> {
+    let data = chunk.data();
+    if data.len() != 96 {
+        return Err("signature chunk must hold a 32-byte public key and a 64-byte signature".into());
+    }
+
+    let verifying_key = VerifyingKey::from_bytes(data[0..32].try_into()?)?;
+    let signature = Signature::from_bytes(data[32..96].try_into()?);
+
+    Ok(verifying_key.verify(digest, &signature).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7u8; 32])
+    }
+
+    #[test]
+    fn test_verify_signature_round_trip() {
+        let key = signing_key();
+        let digest = [1u8; 32];
+        let chunk = signature_chunk(&key, &digest).unwrap();
+
+        assert!(verify_signature(&chunk, &digest).unwrap());
+    }
+
+    #[test]
+    fn test_verify_signature_wrong_digest_fails() {
+        let key = signing_key();
+        let chunk = signature_chunk(&key, &[1u8; 32]).unwrap();
+
+        assert!(!verify_signature(&chunk, &[2u8; 32]).unwrap());
+    }
+
+    #[test]
+    fn test_verify_signature_tampered_chunk_fails() {
+        let key = signing_key();
+        let digest = [1u8; 32];
+        let chunk = signature_chunk(&key, &digest).unwrap();
+
+        let mut data = chunk.data().to_vec();
+        let last = data.len() - 1;
+        data[last] ^= 0xff;
+        let tampered = Chunk::new(chunk.chunk_type().clone(), data);
+
+        assert!(!verify_signature(&tampered, &digest).unwrap());
+    }
+
+    #[test]
+    fn test_verify_signature_wrong_length_fails() {
+        let chunk = Chunk::new(ChunkType::from_str(SIGNATURE_CHUNK_TYPE).unwrap(), vec![0u8; 10]);
+
+        assert!(verify_signature(&chunk, &[1u8; 32]).is_err());
+    }
+}
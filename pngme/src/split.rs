@@ -0,0 +1,153 @@
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+const MAGIC: [u8; 4] = *b"PmSp";
+const HEADER_LEN: usize = 4 + 2 + 2 + 4;
+
+pub fn split_into_chunks(chunk_type: ChunkType, payload: &[u8], piece_size: usize) -> Result<Vec<Chunk>> {
+    if piece_size == 0 {
+        return Err("--split size must be greater than zero".into());
+    }
+
+    let total_len = payload.len() as u32;
+    let bodies: Vec<&[u8]> = if payload.is_empty() {
+        vec![&payload[0..0]]
+    } else {
+        payload.chunks(piece_size).collect()
+    };
+
+    let total: u16 = bodies
+        .len()
+        .try_into()
+        .map_err(|_| "payload needs more than 65535 split chunks; increase --split")?;
+
+    Ok(bodies
+        .into_iter()
+        .enumerate()
+        .map(|(seq, body)| {
+            let mut message_bytes = Vec::with_capacity(HEADER_LEN + body.len());
+            message_bytes.extend_from_slice(&MAGIC);
+            message_bytes.extend_from_slice(&(seq as u16).to_be_bytes());
+            message_bytes.extend_from_slice(&total.to_be_bytes());
+            message_bytes.extend_from_slice(&total_len.to_be_bytes());
+            message_bytes.extend_from_slice(body);
+            Chunk::new(chunk_type.clone(), message_bytes)
+        })
+        .collect())
+}
+
+pub fn is_split_piece(data: &[u8]) -> bool {
+    data.len() >= HEADER_LEN && data[0..4] == MAGIC
+}
+
+struct Piece {
+    seq: u16,
+    total: u16,
+    total_len: u32,
+    body: Vec<u8>,
+}
+
+fn parse_piece(data: &[u8]) -> Result<Piece> {
+    if data.len() < HEADER_LEN {
+        return Err("truncated split chunk header".into());
+    }
+    if data[0..4] != MAGIC {
+        return Err("not a split chunk".into());
+    }
+
+    Ok(Piece {
+        seq: u16::from_be_bytes(data[4..6].try_into()?),
+        total: u16::from_be_bytes(data[6..8].try_into()?),
+        total_len: u32::from_be_bytes(data[8..12].try_into()?),
+        body: data[HEADER_LEN..].to_vec(),
+    })
+}
+
+pub fn reassemble(chunks: &[&Chunk]) -> Result<Vec<u8>> {
+    let mut pieces = chunks
+        .iter()
+        .map(|chunk| parse_piece(chunk.data()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let total = pieces.first().ok_or("no chunks to reassemble")?.total;
+
+    if pieces.len() != total as usize {
+        return Err(format!("expected {} split chunks but found {}", total, pieces.len()).into());
+    }
+
+    let mut seen = vec![false; total as usize];
+    for piece in &pieces {
+        if piece.total != total {
+            return Err("split chunks disagree on the total chunk count".into());
+        }
+        if piece.seq as usize >= total as usize || seen[piece.seq as usize] {
+            return Err(format!("missing or duplicate split chunk index {}", piece.seq).into());
+        }
+        seen[piece.seq as usize] = true;
+    }
+
+    pieces.sort_by_key(|piece| piece.seq);
+
+    let total_len = pieces[0].total_len as usize;
+    let mut payload = Vec::with_capacity(total_len);
+    for piece in pieces {
+        payload.extend(piece.body);
+    }
+
+    if payload.len() != total_len {
+        return Err(format!(
+            "reassembled payload is {} bytes but the header says {}",
+            payload.len(),
+            total_len
+        )
+        .into());
+    }
+
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_round_trip() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let payload = b"this payload spans multiple chunks of split data";
+        let chunks = split_into_chunks(chunk_type, payload, 10).unwrap();
+        let refs: Vec<&Chunk> = chunks.iter().collect();
+
+        assert_eq!(reassemble(&refs).unwrap(), payload.to_vec());
+    }
+
+    #[test]
+    fn test_reassemble_missing_index_fails() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let chunks = split_into_chunks(chunk_type, b"0123456789abcdef", 4).unwrap();
+        let refs: Vec<&Chunk> = chunks.iter().skip(1).collect();
+
+        assert!(reassemble(&refs).is_err());
+    }
+
+    #[test]
+    fn test_reassemble_duplicate_index_fails() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let chunks = split_into_chunks(chunk_type, b"0123456789abcdef", 4).unwrap();
+        let mut refs: Vec<&Chunk> = chunks.iter().collect();
+        refs.push(&chunks[0]);
+
+        assert!(reassemble(&refs).is_err());
+    }
+
+    #[test]
+    fn test_reassemble_truncated_header_fails() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let bad_chunk = Chunk::new(chunk_type, MAGIC[0..2].to_vec());
+
+        assert!(reassemble(&[&bad_chunk]).is_err());
+    }
+}